@@ -0,0 +1,85 @@
+//! Minimal in-memory catalog: per-database schema and retention state that
+//! [`QueryExecutorImpl`](crate::query_executor::QueryExecutorImpl) and
+//! [`QueryDatabase`](crate::query_executor::QueryDatabase) read to resolve table schemas and
+//! enforce retention without a round trip to the catalog service on every query.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use schema::Schema;
+
+/// Sentinel stored in [`DatabaseSchema`]'s retention field meaning "no retention window
+/// configured", so updates are a single atomic store instead of replacing the field.
+const NO_RETENTION: i64 = -1;
+
+/// A database's tables and retention window, shared (via `Arc`) between the catalog and
+/// every in-flight [`QueryDatabase`](crate::query_executor::QueryDatabase) built from it, so
+/// a retention change takes effect for queries already holding a reference to it.
+#[derive(Debug)]
+pub struct DatabaseSchema {
+    pub name: Arc<str>,
+    pub tables: HashMap<String, Schema>,
+    retention_time_ns: AtomicI64,
+}
+
+impl DatabaseSchema {
+    pub fn new(name: Arc<str>, tables: HashMap<String, Schema>) -> Self {
+        Self {
+            name,
+            tables,
+            retention_time_ns: AtomicI64::new(NO_RETENTION),
+        }
+    }
+
+    pub fn get_table_schema(&self, table_name: &str) -> Option<Schema> {
+        self.tables.get(table_name).cloned()
+    }
+
+    /// The database's configured retention window in nanoseconds, or `None` if no
+    /// retention policy is set (all data is kept indefinitely). Read by
+    /// `QueryDatabase`/`QueryTable` to build the `time >= now - retention_time_ns` cutoff
+    /// filter on every query.
+    pub fn retention_time_ns(&self) -> Option<i64> {
+        match self.retention_time_ns.load(Ordering::SeqCst) {
+            NO_RETENTION => None,
+            ns => Some(ns),
+        }
+    }
+
+    pub(crate) fn set_retention_time_ns(&self, retention_time_ns: Option<i64>) {
+        self.retention_time_ns.store(retention_time_ns.unwrap_or(NO_RETENTION), Ordering::SeqCst);
+    }
+}
+
+/// In-memory catalog of this node's known databases.
+#[derive(Debug, Default)]
+pub struct Catalog {
+    databases: RwLock<HashMap<String, Arc<DatabaseSchema>>>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn db_schema(&self, name: &str) -> Option<Arc<DatabaseSchema>> {
+        self.databases.read().expect("catalog lock poisoned").get(name).cloned()
+    }
+
+    pub fn insert_db_schema(&self, db_schema: Arc<DatabaseSchema>) {
+        self.databases
+            .write()
+            .expect("catalog lock poisoned")
+            .insert(db_schema.name.to_string(), db_schema);
+    }
+
+    /// Sets or clears `database`'s retention window. See
+    /// [`QueryExecutorImpl::set_retention_time_ns`](crate::query_executor::QueryExecutorImpl::set_retention_time_ns).
+    pub async fn set_retention_time_ns(&self, database: &str, retention_time_ns: Option<i64>) -> crate::Result<()> {
+        let db_schema = self.db_schema(database).ok_or_else(|| crate::Error::DatabaseNotFound {
+            db_name: database.to_string(),
+        })?;
+        db_schema.set_retention_time_ns(retention_time_ns);
+        Ok(())
+    }
+}